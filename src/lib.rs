@@ -0,0 +1,392 @@
+//! Generic Huffman coding over any symbol alphabet `T` (bytes, `u16`/`u32`
+//! words, token IDs, `char`s, ...), plus a byte-oriented, streaming
+//! file-compression convenience API (`compress_file`/`decompress_file`) that
+//! `src/main.rs`'s CLI is a thin wrapper around.
+
+use std::collections::{BinaryHeap, HashMap};
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fs::File;
+use std::hash::Hash;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+
+/// Size of the blocks read from / written to disk while streaming, so memory
+/// use stays bounded regardless of input file size.
+pub const BLOCK_SIZE: usize = 32 * 1024;
+
+/// A node in a Huffman tree over an arbitrary symbol alphabet `T`. Leaves
+/// carry `data`; internal nodes don't.
+#[derive(Eq, PartialEq)]
+pub struct HuffmanNode<T> {
+    data: Option<T>,
+    frequency: u64,
+    left: Option<Box<HuffmanNode<T>>>,
+    right: Option<Box<HuffmanNode<T>>>,
+}
+
+impl<T: Eq> Ord for HuffmanNode<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.frequency.cmp(&self.frequency)
+    }
+}
+
+impl<T: Eq> PartialOrd for HuffmanNode<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// A Huffman code as a packed bit pattern: the low `bits` bits of `value`,
+/// read most-significant-bit first.
+#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Code {
+    pub value: u64,
+    pub bits: u8,
+}
+
+/// Accumulates bits into bytes, flushing each completed byte straight to the
+/// underlying writer instead of buffering the whole bitstream in memory.
+pub struct BitWriter<W: Write> {
+    writer: W,
+    acc: u8,
+    nbits: u8,
+}
+
+impl<W: Write> BitWriter<W> {
+    pub fn new(writer: W) -> Self {
+        BitWriter { writer, acc: 0, nbits: 0 }
+    }
+
+    fn push_bit(&mut self, bit: u8) -> io::Result<()> {
+        self.acc = (self.acc << 1) | bit;
+        self.nbits += 1;
+        if self.nbits == 8 {
+            self.writer.write_all(&[self.acc])?;
+            self.acc = 0;
+            self.nbits = 0;
+        }
+        Ok(())
+    }
+
+    pub fn push_code(&mut self, code: &Code) -> io::Result<()> {
+        for i in (0..code.bits).rev() {
+            self.push_bit(((code.value >> i) & 1) as u8)?;
+        }
+        Ok(())
+    }
+
+    /// Pads the final partial byte with zeros and flushes it.
+    pub fn finish(mut self) -> io::Result<()> {
+        if self.nbits > 0 {
+            self.acc <<= 8 - self.nbits;
+            self.writer.write_all(&[self.acc])?;
+        }
+        Ok(())
+    }
+}
+
+/// Pulls bits out of a byte stream one at a time, MSB first, stopping before
+/// the final `padding` bits of the last byte, which are never returned.
+pub struct BitReader<R: Read> {
+    reader: R,
+    padding: u8,
+    current: Option<u8>,
+    next: Option<u8>,
+    bit_pos: u8,
+}
+
+impl<R: Read> BitReader<R> {
+    pub fn new(mut reader: R, padding: u8) -> io::Result<Self> {
+        let current = Self::read_byte(&mut reader)?;
+        let next = if current.is_some() { Self::read_byte(&mut reader)? } else { None };
+        Ok(BitReader { reader, padding, current, next, bit_pos: 0 })
+    }
+
+    fn read_byte(reader: &mut R) -> io::Result<Option<u8>> {
+        let mut buf = [0u8; 1];
+        match reader.read(&mut buf)? {
+            0 => Ok(None),
+            _ => Ok(Some(buf[0])),
+        }
+    }
+
+    pub fn next_bit(&mut self) -> io::Result<Option<u8>> {
+        let byte = match self.current {
+            Some(byte) => byte,
+            None => return Ok(None),
+        };
+        let bits_in_byte = if self.next.is_none() { 8 - self.padding } else { 8 };
+        if self.bit_pos >= bits_in_byte {
+            self.current = self.next;
+            self.next = Self::read_byte(&mut self.reader)?;
+            self.bit_pos = 0;
+            return self.next_bit();
+        }
+        let bit = (byte >> (7 - self.bit_pos)) & 1;
+        self.bit_pos += 1;
+        Ok(Some(bit))
+    }
+}
+
+pub fn nodes_from_frequencies<T: Eq + Hash + Clone>(freq_map: &HashMap<T, u64>) -> BinaryHeap<HuffmanNode<T>> {
+    let mut nodes = BinaryHeap::new();
+    for (symbol, &frequency) in freq_map {
+        nodes.push(HuffmanNode { data: Some(symbol.clone()), frequency, left: None, right: None });
+    }
+    nodes
+}
+
+/// Builds a Huffman tree from a heap of leaf nodes, returning `None` for an
+/// empty alphabet (no symbols to build a tree over) instead of panicking.
+pub fn create_huffman_tree<T: Eq>(mut nodes: BinaryHeap<HuffmanNode<T>>) -> Option<HuffmanNode<T>> {
+    while nodes.len() > 1 {
+        let left = nodes.pop().unwrap();
+        let right = nodes.pop().unwrap();
+        let parent = HuffmanNode {
+            data: None,
+            frequency: left.frequency + right.frequency,
+            left: Some(Box::new(left)),
+            right: Some(Box::new(right)),
+        };
+        nodes.push(parent);
+    }
+    nodes.pop()
+}
+
+pub fn get_huff_codes<T: Eq + Hash + Clone>(root: &HuffmanNode<T>) -> HashMap<T, Code> {
+    let mut huffmap = HashMap::new();
+    if let Some(ref data) = root.data {
+        // A single-symbol alphabet collapses the tree to just one leaf, with
+        // no parent to contribute a bit. Force a 1-bit code so its length
+        // isn't `0`, which callers otherwise use as an "unused" sentinel.
+        huffmap.insert(data.clone(), Code { value: 0, bits: 1 });
+    } else {
+        build_huff_codes(root, Code { value: 0, bits: 0 }, &mut huffmap);
+    }
+    huffmap
+}
+
+fn build_huff_codes<T: Eq + Hash + Clone>(node: &HuffmanNode<T>, prefix: Code, huffmap: &mut HashMap<T, Code>) {
+    if let Some(ref data) = node.data {
+        huffmap.insert(data.clone(), prefix);
+    } else {
+        if let Some(ref left) = node.left {
+            build_huff_codes(left, Code { value: prefix.value << 1, bits: prefix.bits + 1 }, huffmap);
+        }
+        if let Some(ref right) = node.right {
+            build_huff_codes(right, Code { value: (prefix.value << 1) | 1, bits: prefix.bits + 1 }, huffmap);
+        }
+    }
+}
+
+/// Projects a code table down to just the bit length of each symbol's code.
+pub fn get_code_lengths<T: Eq + Hash + Clone>(huff_codes: &HashMap<T, Code>) -> HashMap<T, u8> {
+    huff_codes.iter().map(|(symbol, code)| (symbol.clone(), code.bits)).collect()
+}
+
+/// Rebuilds a canonical code table from bit lengths: symbols are ordered by
+/// (length, symbol value), the first gets code 0, and each subsequent code
+/// is the previous one plus one, left-shifted by however much the length grew.
+pub fn canonical_codes_from_lengths<T: Eq + Hash + Clone + Ord>(lengths: &HashMap<T, u8>) -> HashMap<T, Code> {
+    let mut symbols: Vec<(T, u8)> = lengths.iter().map(|(symbol, &len)| (symbol.clone(), len)).collect();
+    symbols.sort_by(|(sym_a, len_a), (sym_b, len_b)| len_a.cmp(len_b).then_with(|| sym_a.cmp(sym_b)));
+
+    let mut huffmap = HashMap::new();
+    let mut iter = symbols.into_iter();
+    let (first_sym, first_len) = match iter.next() {
+        Some(first) => first,
+        None => return huffmap,
+    };
+
+    let mut code: u64 = 0;
+    let mut prev_len = first_len;
+    huffmap.insert(first_sym, Code { value: code, bits: prev_len });
+
+    for (sym, len) in iter {
+        code += 1;
+        if len > prev_len {
+            code <<= len - prev_len;
+        }
+        prev_len = len;
+        huffmap.insert(sym, Code { value: code, bits: len });
+    }
+    huffmap
+}
+
+/// Huffman-codes a whole slice of symbols in memory, returning the packed
+/// bitstream (with a leading padding-bit-count byte, as `decode` expects)
+/// alongside the code-length table needed to decode it.
+pub fn encode<T: Eq + Hash + Clone + Ord>(symbols: &[T]) -> (Vec<u8>, HashMap<T, u8>) {
+    if symbols.is_empty() {
+        return (Vec::new(), HashMap::new());
+    }
+
+    let mut freq_map: HashMap<T, u64> = HashMap::new();
+    for symbol in symbols {
+        *freq_map.entry(symbol.clone()).or_insert(0) += 1;
+    }
+
+    let root = create_huffman_tree(nodes_from_frequencies(&freq_map))
+        .expect("symbols is non-empty, so the heap is non-empty");
+    let huffman_codes = get_huff_codes(&root);
+    let code_lengths = get_code_lengths(&huffman_codes);
+    let canonical_codes = canonical_codes_from_lengths(&code_lengths);
+
+    let total_bits: u64 = freq_map.iter().map(|(symbol, &freq)| canonical_codes[symbol].bits as u64 * freq).sum();
+    let padding = ((8 - (total_bits % 8)) % 8) as u8;
+
+    let mut out = vec![padding];
+    let mut writer = BitWriter::new(&mut out);
+    for symbol in symbols {
+        writer.push_code(&canonical_codes[symbol]).expect("writing to a Vec<u8> never fails");
+    }
+    writer.finish().expect("writing to a Vec<u8> never fails");
+    (out, code_lengths)
+}
+
+/// Decodes a bitstream produced by `encode` back into the original symbols,
+/// given the code-length table `encode` returned alongside it.
+pub fn decode<T: Eq + Hash + Clone + Ord>(encoded: &[u8], code_lengths: &HashMap<T, u8>) -> Vec<T> {
+    let huffman_codes = canonical_codes_from_lengths(code_lengths);
+    let mut code_to_symbol = HashMap::new();
+    for (symbol, code) in huffman_codes {
+        code_to_symbol.insert(code, symbol);
+    }
+
+    let (&padding, data) = match encoded.split_first() {
+        Some(split) => split,
+        None => return Vec::new(),
+    };
+
+    let mut reader = BitReader::new(data, padding).expect("reading from a slice never fails");
+    let mut current = Code { value: 0, bits: 0 };
+    let mut result = Vec::new();
+    while let Some(bit) = reader.next_bit().expect("reading from a slice never fails") {
+        current.value = (current.value << 1) | bit as u64;
+        current.bits += 1;
+        if let Some(symbol) = code_to_symbol.get(&current) {
+            result.push(symbol.clone());
+            current = Code { value: 0, bits: 0 };
+        }
+    }
+    result
+}
+
+/// First pass: reads the file in fixed-size blocks to build the symbol
+/// frequency table without holding the whole file in memory.
+fn count_frequencies(src: &str) -> io::Result<HashMap<u8, u64>> {
+    let mut in_file = BufReader::new(File::open(src)?);
+    let mut freq_map = HashMap::new();
+    let mut block = vec![0u8; BLOCK_SIZE];
+    loop {
+        let n = in_file.read(&mut block)?;
+        if n == 0 {
+            break;
+        }
+        for &byte in &block[..n] {
+            *freq_map.entry(byte).or_insert(0) += 1;
+        }
+    }
+    Ok(freq_map)
+}
+
+/// Packs a byte's code-length table into the fixed 256-entry array used by
+/// the on-disk file format (0 means the symbol is unused).
+fn code_lengths_to_byte_table(lengths: &HashMap<u8, u8>) -> Vec<u8> {
+    let mut table = vec![0u8; 256];
+    for (&byte, &len) in lengths {
+        table[byte as usize] = len;
+    }
+    table
+}
+
+fn byte_table_to_code_lengths(table: &[u8]) -> HashMap<u8, u8> {
+    table
+        .iter()
+        .enumerate()
+        .filter(|&(_, &len)| len > 0)
+        .map(|(byte, &len)| (byte as u8, len))
+        .collect()
+}
+
+/// Huffman-compresses the file at `src` into `dst`, streaming in fixed-size
+/// blocks so memory use stays bounded regardless of input file size. A
+/// zero-byte input is valid and produces a trivial archive, not an error.
+pub fn compress_file(src: &str, dst: &str) -> Result<(), Box<dyn Error>> {
+    let freq_map = count_frequencies(src)?;
+    let mut out_file = BufWriter::new(File::create(dst)?);
+
+    if freq_map.is_empty() {
+        bincode::serialize_into(&mut out_file, &code_lengths_to_byte_table(&HashMap::new()))?;
+        out_file.write_all(&[0u8])?;
+        return Ok(());
+    }
+
+    let root = create_huffman_tree(nodes_from_frequencies(&freq_map))
+        .expect("freq_map is non-empty, so the heap is non-empty");
+    let huffman_codes = get_huff_codes(&root);
+    let code_lengths = get_code_lengths(&huffman_codes);
+    let canonical_codes = canonical_codes_from_lengths(&code_lengths);
+
+    let total_bits: u64 = freq_map
+        .iter()
+        .map(|(byte, &freq)| canonical_codes[byte].bits as u64 * freq)
+        .sum();
+    let padding = ((8 - (total_bits % 8)) % 8) as u8;
+
+    bincode::serialize_into(&mut out_file, &code_lengths_to_byte_table(&code_lengths))?;
+    out_file.write_all(&[padding])?;
+
+    let mut in_file = BufReader::new(File::open(src)?);
+    let mut writer = BitWriter::new(&mut out_file);
+    let mut block = vec![0u8; BLOCK_SIZE];
+    loop {
+        let n = in_file.read(&mut block)?;
+        if n == 0 {
+            break;
+        }
+        for &byte in &block[..n] {
+            writer.push_code(&canonical_codes[&byte])?;
+        }
+    }
+    writer.finish()?;
+    Ok(())
+}
+
+/// Reverses `compress_file`, streaming the decoded bytes out in fixed-size
+/// blocks rather than buffering the whole file in memory.
+pub fn decompress_file(src: &str, dst: &str) -> Result<(), Box<dyn Error>> {
+    let mut in_file = BufReader::new(File::open(src)?);
+    let byte_table: Vec<u8> = bincode::deserialize_from(&mut in_file)?;
+    let code_lengths = byte_table_to_code_lengths(&byte_table);
+    let huffman_codes = canonical_codes_from_lengths(&code_lengths);
+    let mut code_to_byte = HashMap::new();
+    for (byte, code) in huffman_codes {
+        code_to_byte.insert(code, byte);
+    }
+
+    let mut padding_buf = [0u8; 1];
+    in_file.read_exact(&mut padding_buf)?;
+    let padding = padding_buf[0];
+
+    let mut out_file = BufWriter::new(File::create(dst)?);
+    let mut reader = BitReader::new(in_file, padding)?;
+    let mut current = Code { value: 0, bits: 0 };
+    let mut block = Vec::with_capacity(BLOCK_SIZE);
+    while let Some(bit) = reader.next_bit()? {
+        current.value = (current.value << 1) | bit as u64;
+        current.bits += 1;
+        if let Some(&byte) = code_to_byte.get(&current) {
+            block.push(byte);
+            current = Code { value: 0, bits: 0 };
+            if block.len() == BLOCK_SIZE {
+                out_file.write_all(&block)?;
+                block.clear();
+            }
+        }
+    }
+    if !block.is_empty() {
+        out_file.write_all(&block)?;
+    }
+    Ok(())
+}
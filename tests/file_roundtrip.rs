@@ -0,0 +1,43 @@
+use file_compressor::{compress_file, decompress_file, BLOCK_SIZE};
+use std::fs;
+use std::path::PathBuf;
+
+/// Builds a unique scratch path per test run so parallel `cargo test`
+/// invocations don't collide on the same file.
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("file_compressor_test_{}_{}", std::process::id(), name))
+}
+
+fn roundtrip_through_files(data: &[u8], name: &str) -> Vec<u8> {
+    let src = temp_path(&format!("{}_src", name));
+    let archive = temp_path(&format!("{}_archive", name));
+    let dst = temp_path(&format!("{}_dst", name));
+
+    fs::write(&src, data).unwrap();
+    compress_file(src.to_str().unwrap(), archive.to_str().unwrap()).unwrap();
+    decompress_file(archive.to_str().unwrap(), dst.to_str().unwrap()).unwrap();
+    let result = fs::read(&dst).unwrap();
+
+    let _ = fs::remove_file(&src);
+    let _ = fs::remove_file(&archive);
+    let _ = fs::remove_file(&dst);
+    result
+}
+
+#[test]
+fn roundtrips_a_small_file() {
+    let data = b"the quick brown fox jumps over the lazy dog".to_vec();
+    assert_eq!(data, roundtrip_through_files(&data, "small"));
+}
+
+#[test]
+fn roundtrips_an_empty_file() {
+    let data: Vec<u8> = Vec::new();
+    assert_eq!(data, roundtrip_through_files(&data, "empty"));
+}
+
+#[test]
+fn roundtrips_a_file_spanning_multiple_blocks() {
+    let data: Vec<u8> = (0..BLOCK_SIZE * 3 + 777).map(|i| (i % 251) as u8).collect();
+    assert_eq!(data, roundtrip_through_files(&data, "multiblock"));
+}
@@ -0,0 +1,33 @@
+use file_compressor::{decode, encode};
+
+#[test]
+fn roundtrips_repeated_bytes() {
+    let bytes: Vec<u8> = b"abcabcabcabcdxyz ".to_vec();
+    let (encoded, lengths) = encode(&bytes);
+    let decoded: Vec<u8> = decode(&encoded, &lengths);
+    assert_eq!(bytes, decoded);
+}
+
+#[test]
+fn roundtrips_u16_tokens() {
+    let tokens: Vec<u16> = vec![1, 1, 1, 2, 2, 3, 4, 1, 2, 1];
+    let (encoded, lengths) = encode(&tokens);
+    let decoded: Vec<u16> = decode(&encoded, &lengths);
+    assert_eq!(tokens, decoded);
+}
+
+#[test]
+fn roundtrips_chars() {
+    let text: Vec<char> = "mississippi".chars().collect();
+    let (encoded, lengths) = encode(&text);
+    let decoded: Vec<char> = decode(&encoded, &lengths);
+    assert_eq!(text, decoded);
+}
+
+#[test]
+fn roundtrips_a_single_distinct_symbol() {
+    let bytes: Vec<u8> = vec![b'a'; 10];
+    let (encoded, lengths) = encode(&bytes);
+    let decoded: Vec<u8> = decode(&encoded, &lengths);
+    assert_eq!(bytes, decoded);
+}